@@ -0,0 +1,81 @@
+use std::time::{Duration, Instant};
+
+/// The timing summary of one `benchmark` run: the fastest, slowest and mean
+/// measured iteration, and how many iterations were measured. This is a plain
+/// Rust struct; no `benchmark { <block> }` command or record conversion is
+/// defined in this crate.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BenchmarkReport {
+    pub min: Duration,
+    pub max: Duration,
+    pub mean: Duration,
+    pub iterations: usize,
+}
+
+/// Run a warmup iteration (not counted), then `iterations` measured
+/// iterations of `block`, timing each with `Instant` and summarizing the
+/// elapsed durations.
+pub fn benchmark<F: FnMut()>(iterations: usize, mut block: F) -> BenchmarkReport {
+    block();
+
+    if iterations == 0 {
+        return BenchmarkReport {
+            min: Duration::ZERO,
+            max: Duration::ZERO,
+            mean: Duration::ZERO,
+            iterations: 0,
+        };
+    }
+
+    let mut min = Duration::MAX;
+    let mut max = Duration::ZERO;
+    let mut total = Duration::ZERO;
+
+    for _ in 0..iterations {
+        let start = Instant::now();
+        block();
+        let elapsed = start.elapsed();
+
+        min = min.min(elapsed);
+        max = max.max(elapsed);
+        total += elapsed;
+    }
+
+    BenchmarkReport {
+        min,
+        max,
+        mean: total / iterations as u32,
+        iterations,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reports_the_configured_iteration_count() {
+        let report = benchmark(5, || {});
+        assert_eq!(report.iterations, 5);
+    }
+
+    #[test]
+    fn min_never_exceeds_max() {
+        let report = benchmark(10, || {});
+        assert!(report.min <= report.max);
+    }
+
+    #[test]
+    fn zero_iterations_still_runs_the_warmup_and_reports_a_sane_zero_record() {
+        let report = benchmark(0, || {});
+        assert_eq!(
+            report,
+            BenchmarkReport {
+                min: Duration::ZERO,
+                max: Duration::ZERO,
+                mean: Duration::ZERO,
+                iterations: 0,
+            }
+        );
+    }
+}
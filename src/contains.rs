@@ -0,0 +1,120 @@
+use crate::shell_error::ShellError;
+use crate::value::Value;
+
+/// The membership test behind `in`/`not-in`, as a single dispatch on the
+/// container instead of a bespoke comparison path per container kind: list
+/// uses type-aware element equality, string does a substring test, range does
+/// a numeric-bounds test, and record does key-name membership. Adding a new
+/// container type only needs one new match arm here. The operators themselves
+/// are not defined in this crate, and `Value` has no stream variant.
+pub fn contains(container: &Value, elem: &Value) -> Result<bool, ShellError> {
+    match container {
+        Value::List(items) => {
+            if let Some(first) = items.iter().find(|item| !comparable(elem, item)) {
+                return Err(mismatch(elem, first));
+            }
+            Ok(items.iter().any(|item| elem.loosely_equals(item)))
+        }
+        Value::String(haystack) => match elem {
+            Value::String(needle) => Ok(haystack.contains(needle.as_str())),
+            _ => Err(mismatch(elem, container)),
+        },
+        Value::Range(range) => match elem.clone() {
+            value if value.is_numeric() => {
+                let value = match value {
+                    Value::Int(i) => i as f64,
+                    Value::Float(f) => f,
+                    _ => unreachable!("checked by is_numeric"),
+                };
+                Ok(range.contains(value))
+            }
+            _ => Err(mismatch(elem, container)),
+        },
+        Value::Record(fields) => match elem {
+            Value::String(key) => Ok(fields.contains_key(key)),
+            _ => Err(mismatch(elem, container)),
+        },
+        Value::Int(_) | Value::Float(_) => Err(mismatch(elem, container)),
+    }
+}
+
+/// Whether `elem` can even be meaningfully compared against `other` -- numeric
+/// variants compare across int/float, everything else must match exactly.
+fn comparable(elem: &Value, other: &Value) -> bool {
+    if elem.is_numeric() && other.is_numeric() {
+        return true;
+    }
+    std::mem::discriminant(elem) == std::mem::discriminant(other)
+}
+
+fn mismatch(elem: &Value, container: &Value) -> ShellError {
+    ShellError::MismatchedForOperation {
+        lhs_type: elem.type_name().to_string(),
+        rhs_type: container.type_name().to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::range::Range;
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn int_in_list_of_ints() {
+        let list = Value::List(vec![Value::Int(41), Value::Int(42), Value::Int(43)]);
+        assert_eq!(contains(&list, &Value::Int(42)), Ok(true));
+    }
+
+    #[test]
+    fn string_in_list_of_ints_is_a_mismatch() {
+        let list = Value::List(vec![Value::Int(41), Value::Int(42), Value::Int(43)]);
+        let err = contains(&list, &Value::String("hello".into())).unwrap_err();
+        assert_eq!(
+            err,
+            ShellError::MismatchedForOperation {
+                lhs_type: "string".into(),
+                rhs_type: "int".into(),
+            }
+        );
+    }
+
+    #[test]
+    fn int_in_list_of_floats_compares_numerically() {
+        let list = Value::List(vec![
+            Value::Float(1.0),
+            Value::Float(2.5),
+            Value::Float(3.0),
+        ]);
+        assert_eq!(contains(&list, &Value::Int(3)), Ok(true));
+        assert_eq!(contains(&list, &Value::Int(4)), Ok(false));
+    }
+
+    #[test]
+    fn string_substring() {
+        let haystack = Value::String("abc".into());
+        assert_eq!(contains(&haystack, &Value::String("z".into())), Ok(false));
+    }
+
+    #[test]
+    fn non_string_in_string_is_a_mismatch() {
+        let haystack = Value::String("abc".into());
+        assert!(contains(&haystack, &Value::Int(42)).is_err());
+    }
+
+    #[test]
+    fn int_in_range_honors_reversed_bounds() {
+        let range = Value::Range(Range::new_unit(9.42, -4.0, true));
+        assert_eq!(contains(&range, &Value::Int(1)), Ok(true));
+    }
+
+    #[test]
+    fn string_in_record_checks_key_membership() {
+        let mut fields = BTreeMap::new();
+        fields.insert("a".to_string(), Value::Int(13));
+        fields.insert("b".to_string(), Value::Int(14));
+
+        let record = Value::Record(fields);
+        assert_eq!(contains(&record, &Value::String("a".into())), Ok(true));
+    }
+}
@@ -0,0 +1,6 @@
+pub mod benchmark;
+pub mod contains;
+pub mod limits;
+pub mod range;
+pub mod shell_error;
+pub mod value;
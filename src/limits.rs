@@ -0,0 +1,190 @@
+use crate::shell_error::ShellError;
+
+/// Configurable resource guardrails for a single engine run: how deep `def`
+/// calls may nest, how many variables a single scope may bind, and how many
+/// elements a pipeline may carry. Embedders and the CLI tune these via the
+/// setters; the defaults are generous enough for normal scripts while still
+/// catching runaway recursion or scope growth.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EngineLimits {
+    max_depth: usize,
+    max_variables: usize,
+    max_pipeline_length: usize,
+}
+
+impl Default for EngineLimits {
+    fn default() -> Self {
+        EngineLimits {
+            max_depth: 50,
+            max_variables: 1_000,
+            max_pipeline_length: 100_000,
+        }
+    }
+}
+
+impl EngineLimits {
+    pub fn with_max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+
+    pub fn with_max_variables(mut self, max_variables: usize) -> Self {
+        self.max_variables = max_variables;
+        self
+    }
+
+    pub fn with_max_pipeline_length(mut self, max_pipeline_length: usize) -> Self {
+        self.max_pipeline_length = max_pipeline_length;
+        self
+    }
+}
+
+/// Tracks the live counters a single engine run is bound by, incrementing as
+/// the evaluator pushes call frames, binds `let`/parameter variables, and
+/// produces pipeline elements, and decrementing (or resetting) again as each
+/// unwinds or a new pipeline starts.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResourceTracker {
+    limits: EngineLimits,
+    depth: usize,
+    variables_in_scope: Vec<usize>,
+    pipeline_elements: usize,
+}
+
+impl ResourceTracker {
+    pub fn new(limits: EngineLimits) -> Self {
+        ResourceTracker {
+            limits,
+            depth: 0,
+            variables_in_scope: vec![0],
+            pipeline_elements: 0,
+        }
+    }
+
+    /// Push a call frame (and a fresh variable scope with it), failing once
+    /// `max_depth` would be exceeded.
+    pub fn enter_call(&mut self) -> Result<(), ShellError> {
+        if self.depth >= self.limits.max_depth {
+            return Err(ShellError::RecursionLimitReached);
+        }
+        self.depth += 1;
+        self.variables_in_scope.push(0);
+        Ok(())
+    }
+
+    /// Pop the call frame pushed by the matching `enter_call`.
+    pub fn exit_call(&mut self) {
+        self.depth = self.depth.saturating_sub(1);
+        self.variables_in_scope.pop();
+    }
+
+    /// Bind a `let`/parameter variable into the current scope, failing once
+    /// `max_variables` would be exceeded.
+    pub fn bind_variable(&mut self) -> Result<(), ShellError> {
+        let current = self
+            .variables_in_scope
+            .last_mut()
+            .expect("a scope is always present");
+
+        if *current >= self.limits.max_variables {
+            return Err(ShellError::TooManyVariables);
+        }
+
+        *current += 1;
+        Ok(())
+    }
+
+    /// Reset the pipeline-element counter; call this at the start of each new
+    /// pipeline so an earlier pipeline's count doesn't carry over.
+    pub fn start_pipeline(&mut self) {
+        self.pipeline_elements = 0;
+    }
+
+    /// Count one more element flowing through the current pipeline, failing
+    /// once `max_pipeline_length` would be exceeded.
+    pub fn push_pipeline_element(&mut self) -> Result<(), ShellError> {
+        if self.pipeline_elements >= self.limits.max_pipeline_length {
+            return Err(ShellError::TooManyPipelineElements);
+        }
+
+        self.pipeline_elements += 1;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn recursion_past_max_depth_is_rejected() {
+        let limits = EngineLimits::default().with_max_depth(3);
+        let mut tracker = ResourceTracker::new(limits);
+
+        for _ in 0..3 {
+            tracker.enter_call().unwrap();
+        }
+
+        assert_eq!(tracker.enter_call(), Err(ShellError::RecursionLimitReached));
+    }
+
+    #[test]
+    fn unwinding_a_call_frees_its_depth_budget() {
+        let limits = EngineLimits::default().with_max_depth(1);
+        let mut tracker = ResourceTracker::new(limits);
+
+        tracker.enter_call().unwrap();
+        tracker.exit_call();
+
+        assert!(tracker.enter_call().is_ok());
+    }
+
+    #[test]
+    fn binding_past_max_variables_is_rejected() {
+        let limits = EngineLimits::default().with_max_variables(2);
+        let mut tracker = ResourceTracker::new(limits);
+
+        tracker.bind_variable().unwrap();
+        tracker.bind_variable().unwrap();
+
+        assert_eq!(tracker.bind_variable(), Err(ShellError::TooManyVariables));
+    }
+
+    #[test]
+    fn each_scope_gets_its_own_variable_budget() {
+        let limits = EngineLimits::default().with_max_variables(1);
+        let mut tracker = ResourceTracker::new(limits);
+
+        tracker.bind_variable().unwrap();
+        tracker.enter_call().unwrap();
+
+        // A fresh call frame starts a fresh scope, so it isn't penalized by
+        // the outer scope's already-used budget.
+        assert!(tracker.bind_variable().is_ok());
+    }
+
+    #[test]
+    fn pipeline_past_max_length_is_rejected() {
+        let limits = EngineLimits::default().with_max_pipeline_length(2);
+        let mut tracker = ResourceTracker::new(limits);
+
+        tracker.push_pipeline_element().unwrap();
+        tracker.push_pipeline_element().unwrap();
+
+        assert_eq!(
+            tracker.push_pipeline_element(),
+            Err(ShellError::TooManyPipelineElements)
+        );
+    }
+
+    #[test]
+    fn starting_a_new_pipeline_resets_its_element_count() {
+        let limits = EngineLimits::default().with_max_pipeline_length(1);
+        let mut tracker = ResourceTracker::new(limits);
+
+        tracker.push_pipeline_element().unwrap();
+        tracker.start_pipeline();
+
+        assert!(tracker.push_pipeline_element().is_ok());
+    }
+}
@@ -0,0 +1,177 @@
+use crate::shell_error::ShellError;
+
+/// A numeric range with an explicit stride, covering the auto-reversed unit
+/// step form (`4..1`) as well as an explicit stepped form (`1..3..11`, where
+/// the stride is `second - from`).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Range {
+    pub from: f64,
+    pub to: f64,
+    pub step: f64,
+    pub inclusive: bool,
+}
+
+impl Range {
+    /// Build a unit-step range, inferring the step's sign from `from`/`to` the
+    /// way `4..1` auto-reverses to a decreasing stream.
+    pub fn new_unit(from: f64, to: f64, inclusive: bool) -> Self {
+        let step = if to < from { -1.0 } else { 1.0 };
+        Range {
+            from,
+            to,
+            step,
+            inclusive,
+        }
+    }
+
+    /// Build a stepped range from `from..second..to`, where `step` is
+    /// `second - from`.
+    pub fn new_stepped(
+        from: f64,
+        second: f64,
+        to: f64,
+        inclusive: bool,
+    ) -> Result<Self, ShellError> {
+        let step = second - from;
+
+        if step == 0.0 {
+            return Err(ShellError::ZeroStep);
+        }
+
+        if (step > 0.0 && to < from) || (step < 0.0 && to > from) {
+            return Err(ShellError::UnreachableRangeBound);
+        }
+
+        Ok(Range {
+            from,
+            to,
+            step,
+            inclusive,
+        })
+    }
+
+    /// Whether `value` falls within the range's bounds, honoring the
+    /// inclusive/exclusive end and either direction of travel. Membership
+    /// doesn't depend on `step` landing exactly on `value` -- this mirrors
+    /// `in`/`not-in`'s numeric-bounds test, not iteration.
+    pub fn contains(&self, value: f64) -> bool {
+        let (lo, hi) = if self.from <= self.to {
+            (self.from, self.to)
+        } else {
+            (self.to, self.from)
+        };
+
+        if self.inclusive {
+            value >= lo && value <= hi
+        } else if self.from <= self.to {
+            value >= lo && value < hi
+        } else {
+            value > lo && value <= hi
+        }
+    }
+
+    /// Iterate the range's elements, advancing by `step` and stopping once
+    /// advancing further would pass `to` in the step's direction.
+    pub fn iter(&self) -> RangeIter {
+        RangeIter {
+            range: *self,
+            current: self.from,
+            done: false,
+        }
+    }
+}
+
+pub struct RangeIter {
+    range: Range,
+    current: f64,
+    done: bool,
+}
+
+impl Iterator for RangeIter {
+    type Item = f64;
+
+    fn next(&mut self) -> Option<f64> {
+        if self.done {
+            return None;
+        }
+
+        let past_end = if self.range.step > 0.0 {
+            if self.range.inclusive {
+                self.current > self.range.to
+            } else {
+                self.current >= self.range.to
+            }
+        } else if self.range.inclusive {
+            self.current < self.range.to
+        } else {
+            self.current <= self.range.to
+        };
+
+        if past_end {
+            self.done = true;
+            return None;
+        }
+
+        let value = self.current;
+        self.current += self.range.step;
+        Some(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn explicit_step_sequence() {
+        let range = Range::new_stepped(1.0, 3.0, 11.0, true).unwrap();
+        let values: Vec<f64> = range.iter().collect();
+        assert_eq!(values, vec![1.0, 3.0, 5.0, 7.0, 9.0, 11.0]);
+    }
+
+    #[test]
+    fn negative_step_sequence() {
+        let range = Range::new_stepped(10.0, 8.0, 0.0, true).unwrap();
+        let values: Vec<f64> = range.iter().collect();
+        assert_eq!(values, vec![10.0, 8.0, 6.0, 4.0, 2.0, 0.0]);
+    }
+
+    #[test]
+    fn stepped_range_respects_exclusive_end() {
+        let range = Range::new_stepped(0.0, 2.0, 6.0, false).unwrap();
+        let values: Vec<f64> = range.iter().collect();
+        assert_eq!(values, vec![0.0, 2.0, 4.0]);
+    }
+
+    #[test]
+    fn zero_step_is_rejected() {
+        assert_eq!(
+            Range::new_stepped(1.0, 1.0, 10.0, true),
+            Err(ShellError::ZeroStep)
+        );
+    }
+
+    #[test]
+    fn positive_step_toward_unreachable_bound_is_rejected() {
+        assert_eq!(
+            Range::new_stepped(10.0, 12.0, 0.0, true),
+            Err(ShellError::UnreachableRangeBound)
+        );
+    }
+
+    #[test]
+    fn unit_step_auto_reverses() {
+        let range = Range::new_unit(4.0, 1.0, true);
+        let values: Vec<f64> = range.iter().collect();
+        assert_eq!(values, vec![4.0, 3.0, 2.0, 1.0]);
+    }
+
+    #[test]
+    fn contains_honors_reversed_bounds_and_inclusivity() {
+        let dec = Range::new_unit(9.42, -4.0, true);
+        assert!(dec.contains(1.0));
+
+        let exclusive = Range::new_unit(0.0, 3.0, false);
+        assert!(!exclusive.contains(3.0));
+    }
+}
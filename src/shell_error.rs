@@ -0,0 +1,41 @@
+use std::fmt;
+
+/// Errors surfaced by the engine core that aren't tied to any one command.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ShellError {
+    /// The element being searched for can't be compared to the container's
+    /// element type (e.g. a string against a list of ints).
+    MismatchedForOperation { lhs_type: String, rhs_type: String },
+    /// A range step of zero was given; it would never reach `to`.
+    ZeroStep,
+    /// The range's `to` is unreachable in the direction `step` moves (e.g. a
+    /// positive step toward a smaller bound).
+    UnreachableRangeBound,
+    /// The call stack grew past `EngineLimits`' configured `max_depth`.
+    RecursionLimitReached,
+    /// A single scope bound more variables than `EngineLimits`' configured
+    /// `max_variables`.
+    TooManyVariables,
+    /// A pipeline produced more elements than `EngineLimits`' configured
+    /// `max_pipeline_length`.
+    TooManyPipelineElements,
+}
+
+impl fmt::Display for ShellError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ShellError::MismatchedForOperation { lhs_type, rhs_type } => {
+                write!(f, "mismatched for operation: {} and {}", lhs_type, rhs_type)
+            }
+            ShellError::ZeroStep => write!(f, "range step cannot be zero"),
+            ShellError::UnreachableRangeBound => {
+                write!(f, "range step moves away from its unreachable bound")
+            }
+            ShellError::RecursionLimitReached => write!(f, "RecursionLimitReached"),
+            ShellError::TooManyVariables => write!(f, "TooManyVariables"),
+            ShellError::TooManyPipelineElements => write!(f, "TooManyPipelineElements"),
+        }
+    }
+}
+
+impl std::error::Error for ShellError {}
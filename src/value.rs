@@ -0,0 +1,49 @@
+use std::collections::BTreeMap;
+
+use crate::range::Range;
+
+/// A minimal stand-in for the engine's real `Value`, covering just the
+/// variants `contains` needs to dispatch on.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Int(i64),
+    Float(f64),
+    String(String),
+    List(Vec<Value>),
+    Record(BTreeMap<String, Value>),
+    Range(Range),
+}
+
+impl Value {
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            Value::Int(_) => "int",
+            Value::Float(_) => "float",
+            Value::String(_) => "string",
+            Value::List(_) => "list",
+            Value::Record(_) => "record",
+            Value::Range(_) => "range",
+        }
+    }
+
+    fn as_f64(&self) -> Option<f64> {
+        match self {
+            Value::Int(i) => Some(*i as f64),
+            Value::Float(f) => Some(*f),
+            _ => None,
+        }
+    }
+
+    /// Type-aware equality: numeric variants compare by value regardless of
+    /// int/float, everything else falls back to `PartialEq`.
+    pub fn loosely_equals(&self, other: &Value) -> bool {
+        match (self.as_f64(), other.as_f64()) {
+            (Some(a), Some(b)) => a == b,
+            _ => self == other,
+        }
+    }
+
+    pub fn is_numeric(&self) -> bool {
+        matches!(self, Value::Int(_) | Value::Float(_))
+    }
+}